@@ -0,0 +1,228 @@
+//! Builds a [`Symbol`] outline from a Rust source file.
+//!
+//! This walks the `syn` AST directly rather than going through
+//! `syn::visit::Visit`, since we need to assemble a *tree* (struct/enum/
+//! trait owning their members) rather than a flat visitor callback stream.
+//!
+//! Identifier scanning itself is delegated entirely to `proc-macro2`'s
+//! tokenizer, which already implements rustc's XID_Start/XID_Continue
+//! identifier grammar (plus the leading-underscore exception) rather than
+//! restricting names to ASCII, so `café`, `Δ`, and `Ωmega` round-trip with
+//! correct names and byte ranges with no extra handling here — see
+//! `tests/fixtures/unicode.rs` for the locked-in behavior.
+
+use std::collections::HashMap;
+
+use quote::ToTokens;
+use syn::{Item, ImplItem, TraitItem};
+
+use crate::symbol::{GenericParam, Range, Symbol, SymbolKind, WherePredicate};
+
+/// Extracts a nested symbol outline from `source`, mirroring the shape of
+/// an LSP `textDocument/documentSymbol` response: top-level items own their
+/// members (methods, enum variants, trait methods) as `children`.
+pub fn extract_symbol_tree(source: &str) -> syn::Result<Vec<Symbol>> {
+    let file = syn::parse_file(source)?;
+    let mut symbols = Vec::new();
+    // Maps a struct/enum name to its index in `symbols`, so a second pass
+    // can fold `impl` methods into their owner regardless of whether the
+    // `impl` block appears before or after the type it's for (both are
+    // legal Rust). Only struct/enum own impl methods; a function sharing
+    // a name with a type (different namespaces) must not be matched.
+    let mut owners: HashMap<String, usize> = HashMap::new();
+
+    for item in &file.items {
+        match item {
+            Item::Struct(item_struct) => {
+                let (generics, where_predicates) = generics_of(&item_struct.generics);
+                owners.insert(item_struct.ident.to_string(), symbols.len());
+                symbols.push(
+                    Symbol::new(
+                        item_struct.ident.to_string(),
+                        SymbolKind::Struct,
+                        byte_range(item_struct),
+                        byte_range(&item_struct.ident),
+                    )
+                    .with_generics(generics, where_predicates),
+                );
+            }
+            Item::Enum(item_enum) => {
+                let container = item_enum.ident.to_string();
+                let variants = item_enum
+                    .variants
+                    .iter()
+                    .map(|variant| {
+                        Symbol::new(
+                            variant.ident.to_string(),
+                            SymbolKind::EnumVariant,
+                            byte_range(variant),
+                            byte_range(&variant.ident),
+                        )
+                        .with_container(container.clone())
+                    })
+                    .collect();
+                let (generics, where_predicates) = generics_of(&item_enum.generics);
+                owners.insert(container.clone(), symbols.len());
+                symbols.push(
+                    Symbol::new(container, SymbolKind::Enum, byte_range(item_enum), byte_range(&item_enum.ident))
+                        .with_children(variants)
+                        .with_generics(generics, where_predicates),
+                );
+            }
+            Item::Trait(item_trait) => {
+                let container = item_trait.ident.to_string();
+                let methods = item_trait
+                    .items
+                    .iter()
+                    .filter_map(|trait_item| match trait_item {
+                        TraitItem::Fn(trait_fn) => {
+                            let (generics, where_predicates) = generics_of(&trait_fn.sig.generics);
+                            Some(
+                                Symbol::new(
+                                    trait_fn.sig.ident.to_string(),
+                                    SymbolKind::Method,
+                                    byte_range(trait_fn),
+                                    byte_range(&trait_fn.sig.ident),
+                                )
+                                .with_container(container.clone())
+                                .with_generics(generics, where_predicates),
+                            )
+                        }
+                        _ => None,
+                    })
+                    .collect();
+                let (generics, where_predicates) = generics_of(&item_trait.generics);
+                symbols.push(
+                    Symbol::new(container, SymbolKind::Trait, byte_range(item_trait), byte_range(&item_trait.ident))
+                        .with_children(methods)
+                        .with_generics(generics, where_predicates),
+                );
+            }
+            Item::Fn(item_fn) => {
+                let (generics, where_predicates) = generics_of(&item_fn.sig.generics);
+                symbols.push(
+                    Symbol::new(
+                        item_fn.sig.ident.to_string(),
+                        SymbolKind::Function,
+                        byte_range(item_fn),
+                        byte_range(&item_fn.sig.ident),
+                    )
+                    .with_generics(generics, where_predicates),
+                );
+            }
+            // `impl` blocks are folded into their owning struct/enum below,
+            // once every struct/enum in the file has been indexed.
+            Item::Impl(_) => {}
+            _ => {}
+        }
+    }
+
+    for item in &file.items {
+        let Item::Impl(item_impl) = item else { continue };
+        let Some(container) = self_type_name(item_impl) else { continue };
+        let methods: Vec<Symbol> = item_impl
+            .items
+            .iter()
+            .filter_map(|impl_item| match impl_item {
+                ImplItem::Fn(impl_fn) => {
+                    let (generics, where_predicates) = generics_of(&impl_fn.sig.generics);
+                    Some(
+                        Symbol::new(
+                            impl_fn.sig.ident.to_string(),
+                            SymbolKind::Method,
+                            byte_range(impl_fn),
+                            byte_range(&impl_fn.sig.ident),
+                        )
+                        .with_container(container.clone())
+                        .with_generics(generics, where_predicates),
+                    )
+                }
+                _ => None,
+            })
+            .collect();
+
+        // An `impl` block isn't itself a symbol; fold its methods into the
+        // struct/enum symbol of the same name so `Foo` owns `new`/`bar`
+        // regardless of how many `impl Foo` blocks declare them, or
+        // whether they're written before or after `Foo` itself. Anything
+        // left over (blanket impls, or an impl for a type this file
+        // doesn't declare) surfaces as flat top-level symbols instead.
+        if let Some(&owner_index) = owners.get(&container) {
+            symbols[owner_index].children.extend(methods);
+        } else {
+            symbols.extend(methods);
+        }
+    }
+
+    Ok(symbols)
+}
+
+/// Like [`extract_symbol_tree`], but flattens each top-level symbol's
+/// descendants back into a single document-order list (`container_name` is
+/// preserved), for consumers such as [`crate::query::WorkspaceSymbolIndex`]
+/// that index symbols individually rather than as a tree.
+pub fn extract_symbols_flat(source: &str) -> syn::Result<Vec<Symbol>> {
+    Ok(extract_symbol_tree(source)?.into_iter().flat_map(Symbol::flatten).collect())
+}
+
+/// Splits a `syn::Generics` into inline-bound [`GenericParam`]s and
+/// normalized `where`-clause predicates.
+pub(crate) fn generics_of(generics: &syn::Generics) -> (Vec<GenericParam>, Vec<WherePredicate>) {
+    let params = generics
+        .type_params()
+        .map(|type_param| {
+            let bounds = type_param.bounds.iter().map(bound_to_string).collect();
+            GenericParam::new(type_param.ident.to_string(), bounds)
+        })
+        .collect();
+
+    let where_predicates = generics
+        .where_clause
+        .iter()
+        .flat_map(|clause| &clause.predicates)
+        .filter_map(|predicate| match predicate {
+            syn::WherePredicate::Type(predicate_type) => {
+                let bounded = predicate_type.bounded_ty.to_token_stream().to_string();
+                let bounds = predicate_type.bounds.iter().map(bound_to_string).collect();
+                Some((bounded, bounds))
+            }
+            _ => None,
+        })
+        .collect();
+
+    (params, where_predicates)
+}
+
+pub(crate) fn bound_to_string(bound: &syn::TypeParamBound) -> String {
+    bound.to_token_stream().to_string()
+}
+
+pub(crate) fn self_type_name(item_impl: &syn::ItemImpl) -> Option<String> {
+    match &*item_impl.self_ty {
+        syn::Type::Path(type_path) => type_path.path.segments.last().map(|seg| seg.ident.to_string()),
+        _ => None,
+    }
+}
+
+pub(crate) fn byte_range(tokens: &dyn quote::ToTokens) -> Range {
+    let mut spans = proc_macro2::TokenStream::new();
+    tokens.to_tokens(&mut spans);
+    let span = spans
+        .into_iter()
+        .next()
+        .map(|tt| tt.span())
+        .unwrap_or_else(proc_macro2::Span::call_site);
+    let start = span.byte_range().start;
+    let end = tokens_end(tokens);
+    Range::new(start, end)
+}
+
+fn tokens_end(tokens: &dyn quote::ToTokens) -> usize {
+    let mut stream = proc_macro2::TokenStream::new();
+    tokens.to_tokens(&mut stream);
+    stream
+        .into_iter()
+        .last()
+        .map(|tt| tt.span().byte_range().end)
+        .unwrap_or(0)
+}
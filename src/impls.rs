@@ -0,0 +1,106 @@
+//! Indexes `impl` blocks so "who implements `Trait`?" and "what does
+//! `Type` implement?" can be answered directly, without re-walking the
+//! symbol tree for every query.
+
+use serde::Serialize;
+use syn::{Item, ImplItem};
+
+use crate::extract::{byte_range, generics_of, self_type_name};
+use crate::symbol::{GenericParam, Range, Symbol, SymbolKind, WherePredicate};
+
+/// A single `impl` block: the type it's for, the trait it implements (if
+/// any), and the methods it defines.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Implementation {
+    /// The type being implemented for, e.g. `"Foo"` for `impl Foo`, or the
+    /// name of the impl's own generic parameter for a blanket impl, e.g.
+    /// `"T"` for `impl<T> Equal for T`.
+    pub type_name: String,
+    /// `None` for an inherent impl (`impl Foo { .. }`).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trait_name: Option<String>,
+    /// True when `type_name` is one of the impl block's own generic
+    /// parameters, i.e. this is a blanket impl.
+    pub is_blanket: bool,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub generics: Vec<GenericParam>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub where_predicates: Vec<WherePredicate>,
+    pub range: Range,
+    pub methods: Vec<Symbol>,
+}
+
+/// Links traits to the types that implement them and vice versa.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct TraitImplIndex {
+    impls: Vec<Implementation>,
+}
+
+impl TraitImplIndex {
+    /// Walks `source` and indexes every `impl` block it contains.
+    pub fn build(source: &str) -> syn::Result<Self> {
+        let file = syn::parse_file(source)?;
+        let mut impls = Vec::new();
+
+        for item in &file.items {
+            let Item::Impl(item_impl) = item else { continue };
+            let Some(type_name) = self_type_name(item_impl) else { continue };
+
+            let trait_name = item_impl
+                .trait_
+                .as_ref()
+                .and_then(|(_, path, _)| path.segments.last())
+                .map(|segment| segment.ident.to_string());
+            let is_blanket = item_impl.generics.type_params().any(|tp| tp.ident == type_name);
+            let (generics, where_predicates) = generics_of(&item_impl.generics);
+
+            let methods = item_impl
+                .items
+                .iter()
+                .filter_map(|impl_item| match impl_item {
+                    ImplItem::Fn(impl_fn) => {
+                        let (fn_generics, fn_where) = generics_of(&impl_fn.sig.generics);
+                        Some(
+                            Symbol::new(
+                                impl_fn.sig.ident.to_string(),
+                                SymbolKind::Method,
+                                byte_range(impl_fn),
+                                byte_range(&impl_fn.sig.ident),
+                            )
+                            .with_container(type_name.clone())
+                            .with_generics(fn_generics, fn_where),
+                        )
+                    }
+                    _ => None,
+                })
+                .collect();
+
+            impls.push(Implementation {
+                type_name,
+                trait_name,
+                is_blanket,
+                generics,
+                where_predicates,
+                range: byte_range(item_impl),
+                methods,
+            });
+        }
+
+        Ok(Self { impls })
+    }
+
+    /// Every indexed impl, in source order.
+    pub fn all(&self) -> &[Implementation] {
+        &self.impls
+    }
+
+    /// Every impl of the trait named `trait_name`, including blanket impls.
+    pub fn implementors_of(&self, trait_name: &str) -> Vec<&Implementation> {
+        self.impls.iter().filter(|imp| imp.trait_name.as_deref() == Some(trait_name)).collect()
+    }
+
+    /// Every impl block (inherent or trait) for the type named `type_name`.
+    pub fn implementations_for(&self, type_name: &str) -> Vec<&Implementation> {
+        self.impls.iter().filter(|imp| imp.type_name == type_name).collect()
+    }
+}
@@ -0,0 +1,11 @@
+//! Symbol extraction and indexing for source files.
+
+pub mod extract;
+pub mod impls;
+pub mod query;
+pub mod symbol;
+
+pub use extract::{extract_symbol_tree, extract_symbols_flat};
+pub use impls::{Implementation, TraitImplIndex};
+pub use query::{parse_query, WorkspaceSymbolIndex};
+pub use symbol::{Range, Symbol, SymbolKind};
@@ -0,0 +1,204 @@
+//! Fuzzy workspace-symbol search, rust-analyzer style.
+//!
+//! A query string is a bare term plus optional trailing modifiers:
+//!
+//! - `Foo` — fuzzy-match type-like symbols (struct/enum/trait) in the
+//!   current workspace.
+//! - `bar#` — the `#` suffix switches the match to functions/methods.
+//! - `Foo*` — the `*` suffix widens the search from the workspace to
+//!   indexed dependencies as well.
+//! - `bar#*` — both modifiers combined.
+
+use crate::symbol::{Symbol, SymbolKind};
+
+/// Which symbol kinds a query matches against.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KindFilter {
+    /// struct / enum / trait
+    Type,
+    /// free functions and methods
+    Function,
+}
+
+impl KindFilter {
+    fn matches(self, kind: SymbolKind) -> bool {
+        match self {
+            KindFilter::Type => matches!(kind, SymbolKind::Struct | SymbolKind::Enum | SymbolKind::Trait),
+            KindFilter::Function => matches!(kind, SymbolKind::Function | SymbolKind::Method),
+        }
+    }
+}
+
+/// Which symbol sources a query is allowed to search.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScopeFilter {
+    /// Only symbols defined in the current workspace.
+    Workspace,
+    /// Workspace symbols plus symbols indexed from dependencies.
+    WorkspaceAndDependencies,
+}
+
+/// A query string split into its search term and modifiers.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParsedQuery {
+    pub term: String,
+    pub kind_filter: KindFilter,
+    pub scope_filter: ScopeFilter,
+}
+
+/// Strips the `#`/`*` modifier suffixes from `input`, in either order,
+/// leaving the plain search term.
+pub fn parse_query(input: &str) -> ParsedQuery {
+    let mut term = input;
+    let mut kind_filter = KindFilter::Type;
+    let mut scope_filter = ScopeFilter::Workspace;
+
+    // Modifiers may appear in either order (`foo#*` or `foo*#`), so peel
+    // them off one at a time from the end.
+    loop {
+        if let Some(rest) = term.strip_suffix('#') {
+            kind_filter = KindFilter::Function;
+            term = rest;
+        } else if let Some(rest) = term.strip_suffix('*') {
+            scope_filter = ScopeFilter::WorkspaceAndDependencies;
+            term = rest;
+        } else {
+            break;
+        }
+    }
+
+    ParsedQuery { term: term.to_string(), kind_filter, scope_filter }
+}
+
+/// An index of extracted symbols, split by whether they come from the
+/// workspace itself or from indexed dependencies.
+#[derive(Debug, Clone, Default)]
+pub struct WorkspaceSymbolIndex {
+    pub workspace: Vec<Symbol>,
+    pub dependencies: Vec<Symbol>,
+}
+
+impl WorkspaceSymbolIndex {
+    pub fn new(workspace: Vec<Symbol>) -> Self {
+        Self { workspace, dependencies: Vec::new() }
+    }
+
+    /// Runs a rust-analyzer-style query (see module docs for syntax) and
+    /// returns matches ranked best-first.
+    pub fn query(&self, input: &str) -> Vec<(&Symbol, i32)> {
+        let parsed = parse_query(input);
+        let symbols = match parsed.scope_filter {
+            ScopeFilter::Workspace => self.workspace.iter(),
+            ScopeFilter::WorkspaceAndDependencies => {
+                // `.chain` would need a boxed iterator to unify with the
+                // workspace-only arm, so just collect both halves instead.
+                let mut all: Vec<&Symbol> = self.workspace.iter().collect();
+                all.extend(self.dependencies.iter());
+                return rank(all.into_iter(), &parsed);
+            }
+        };
+        rank(symbols, &parsed)
+    }
+}
+
+fn rank<'a>(symbols: impl Iterator<Item = &'a Symbol>, parsed: &ParsedQuery) -> Vec<(&'a Symbol, i32)> {
+    let mut matches: Vec<(&Symbol, i32)> = symbols
+        .filter(|s| parsed.kind_filter.matches(s.kind))
+        .filter_map(|s| fuzzy_score(&parsed.term, &s.name).map(|score| (s, score)))
+        .collect();
+    matches.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.name.cmp(&b.0.name)));
+    matches
+}
+
+/// Scores `candidate` against `query` as a subsequence match, returning
+/// `None` if `query` isn't a subsequence of `candidate` (case-insensitive).
+///
+/// Bonuses are awarded for runs of contiguous matched characters and for
+/// "camel-hump" matches (matching the start of a new word: after `_`, or
+/// an uppercase letter following a lowercase one), mirroring the heuristics
+/// fuzzy finders like fzf and rust-analyzer's `fst`-based matcher use.
+pub fn fuzzy_score(query: &str, candidate: &str) -> Option<i32> {
+    if query.is_empty() {
+        return Some(0);
+    }
+
+    let query_chars: Vec<char> = query.chars().collect();
+    let candidate_chars: Vec<char> = candidate.chars().collect();
+
+    let mut score = 0i32;
+    let mut qi = 0;
+    let mut prev_matched_at: Option<usize> = None;
+
+    for (ci, &c) in candidate_chars.iter().enumerate() {
+        if qi >= query_chars.len() {
+            break;
+        }
+        if !c.eq_ignore_ascii_case(&query_chars[qi]) {
+            continue;
+        }
+
+        let mut char_score = 1;
+        if prev_matched_at == Some(ci.wrapping_sub(1)) {
+            char_score += 5; // contiguous run
+        }
+        let is_word_start = ci == 0
+            || candidate_chars[ci - 1] == '_'
+            || (c.is_uppercase() && candidate_chars[ci - 1].is_lowercase());
+        if is_word_start {
+            char_score += 3; // camel-hump / word-boundary bonus
+        }
+
+        score += char_score;
+        prev_matched_at = Some(ci);
+        qi += 1;
+    }
+
+    if qi == query_chars.len() {
+        Some(score)
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_bare_term() {
+        let q = parse_query("Foo");
+        assert_eq!(q.term, "Foo");
+        assert_eq!(q.kind_filter, KindFilter::Type);
+        assert_eq!(q.scope_filter, ScopeFilter::Workspace);
+    }
+
+    #[test]
+    fn parses_function_modifier() {
+        let q = parse_query("bar#");
+        assert_eq!(q.term, "bar");
+        assert_eq!(q.kind_filter, KindFilter::Function);
+        assert_eq!(q.scope_filter, ScopeFilter::Workspace);
+    }
+
+    #[test]
+    fn parses_combined_modifiers_in_either_order() {
+        for input in ["Foo#*", "Foo*#"] {
+            let q = parse_query(input);
+            assert_eq!(q.term, "Foo");
+            assert_eq!(q.kind_filter, KindFilter::Function);
+            assert_eq!(q.scope_filter, ScopeFilter::WorkspaceAndDependencies);
+        }
+    }
+
+    #[test]
+    fn fuzzy_score_rejects_non_subsequence() {
+        assert_eq!(fuzzy_score("xyz", "Foo"), None);
+    }
+
+    #[test]
+    fn fuzzy_score_rewards_contiguous_and_word_start_matches() {
+        let contiguous = fuzzy_score("Foo", "Foo").unwrap();
+        let scattered = fuzzy_score("Foo", "Farmyard Orchard Oasis").unwrap();
+        assert!(contiguous > scattered);
+    }
+}
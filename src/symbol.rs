@@ -0,0 +1,127 @@
+use serde::Serialize;
+
+/// A byte offset into the original source text.
+pub type Offset = usize;
+
+/// A byte range `[start, end)` into the original source text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct Range {
+    pub start: Offset,
+    pub end: Offset,
+}
+
+impl Range {
+    pub fn new(start: Offset, end: Offset) -> Self {
+        Self { start, end }
+    }
+}
+
+/// The kind of language construct a [`Symbol`] represents.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SymbolKind {
+    Struct,
+    Enum,
+    EnumVariant,
+    Trait,
+    Function,
+    Method,
+}
+
+/// A single generic type parameter, with any bounds written inline
+/// (`T: Eq`) rather than in a trailing `where` clause.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Default)]
+pub struct GenericParam {
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub bounds: Vec<String>,
+}
+
+impl GenericParam {
+    pub fn new(name: impl Into<String>, bounds: Vec<String>) -> Self {
+        Self { name: name.into(), bounds }
+    }
+}
+
+/// A normalized `where`-clause predicate: the bounded type (as written,
+/// e.g. `"T"` or `"Vec<T>"`) and the bounds applied to it.
+pub type WherePredicate = (String, Vec<String>);
+
+/// A symbol extracted from source.
+///
+/// Symbols form a tree: a method's `children` is empty but its
+/// `container_name` points at the enclosing `impl`/`trait` type, while the
+/// enclosing struct/enum/trait carries the method or variant directly in
+/// `children`. Consumers that only want the old flat shape can call
+/// [`Symbol::flatten`].
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Symbol {
+    pub name: String,
+    pub kind: SymbolKind,
+    /// Name of the symbol's immediate container, if any (e.g. `"Foo"` for
+    /// a method defined in `impl Foo`).
+    pub container_name: Option<String>,
+    /// The full source range of the symbol, including its body.
+    pub range: Range,
+    /// The narrower range to use for highlighting/navigating to the symbol
+    /// itself (typically just its name), as used for outline breadcrumbs.
+    pub selection_range: Range,
+    /// Symbols lexically nested inside this one.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub children: Vec<Symbol>,
+    /// This symbol's own generic parameters and their inline bounds, e.g.
+    /// `<T: Eq, U>` on `pub fn equals<T: Eq, U>(...)`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub generics: Vec<GenericParam>,
+    /// Normalized `where`-clause predicates, e.g. `[("U", ["Eq"])]` for
+    /// `where U: Eq`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub where_predicates: Vec<WherePredicate>,
+}
+
+impl Symbol {
+    pub fn new(name: impl Into<String>, kind: SymbolKind, range: Range, selection_range: Range) -> Self {
+        Self {
+            name: name.into(),
+            kind,
+            container_name: None,
+            range,
+            selection_range,
+            children: Vec::new(),
+            generics: Vec::new(),
+            where_predicates: Vec::new(),
+        }
+    }
+
+    pub fn with_container(mut self, container_name: impl Into<String>) -> Self {
+        self.container_name = Some(container_name.into());
+        self
+    }
+
+    pub fn with_children(mut self, children: Vec<Symbol>) -> Self {
+        self.children = children;
+        self
+    }
+
+    pub fn with_generics(mut self, generics: Vec<GenericParam>, where_predicates: Vec<WherePredicate>) -> Self {
+        self.generics = generics;
+        self.where_predicates = where_predicates;
+        self
+    }
+
+    /// Flattens this symbol and its descendants into the pre-tree shape:
+    /// a single list in document order, with `container_name` still set.
+    pub fn flatten(self) -> Vec<Symbol> {
+        let mut out = Vec::new();
+        flatten_into(self, &mut out);
+        out
+    }
+}
+
+fn flatten_into(mut symbol: Symbol, out: &mut Vec<Symbol>) {
+    let children = std::mem::take(&mut symbol.children);
+    out.push(symbol);
+    for child in children {
+        flatten_into(child, out);
+    }
+}
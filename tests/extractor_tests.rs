@@ -0,0 +1,58 @@
+mod support;
+
+use std::io::Write;
+
+use codekite::{extract_symbol_tree, SymbolKind};
+use support::mint;
+
+const GOLDEN_RUST: &str = include_str!("golden_rust.rs");
+const GENERICS_RUST: &str = include_str!("fixtures/generics.rs");
+const UNICODE_RUST: &str = include_str!("fixtures/unicode.rs");
+const IMPL_BEFORE_DECL_RUST: &str = include_str!("fixtures/impl_before_decl.rs");
+
+#[test]
+fn nested_outline_for_golden_rust() {
+    let symbols = extract_symbol_tree(GOLDEN_RUST).expect("golden_rust.rs should parse");
+    let mut golden = mint().new_goldenfile("golden_rust.nested.json");
+    write!(golden, "{}", serde_json::to_string_pretty(&symbols).unwrap()).unwrap();
+}
+
+#[test]
+fn generics_and_where_clauses_are_captured() {
+    let symbols = extract_symbol_tree(GENERICS_RUST).expect("generics.rs should parse");
+    let mut golden = mint().new_goldenfile("generics.json");
+    write!(golden, "{}", serde_json::to_string_pretty(&symbols).unwrap()).unwrap();
+}
+
+#[test]
+fn unicode_identifiers_keep_correct_names_and_byte_ranges() {
+    let symbols = extract_symbol_tree(UNICODE_RUST).expect("unicode.rs should parse");
+    let mut golden = mint().new_goldenfile("unicode.json");
+    write!(golden, "{}", serde_json::to_string_pretty(&symbols).unwrap()).unwrap();
+}
+
+#[test]
+fn impl_methods_fold_into_their_owner_regardless_of_declaration_order() {
+    let symbols = extract_symbol_tree(IMPL_BEFORE_DECL_RUST).expect("impl_before_decl.rs should parse");
+
+    let foo = symbols.iter().find(|s| s.name == "Foo").expect("Foo struct should be extracted");
+    assert_eq!(foo.kind, SymbolKind::Struct);
+    assert_eq!(foo.children.iter().map(|c| c.name.as_str()).collect::<Vec<_>>(), vec!["new"]);
+}
+
+#[test]
+fn impl_methods_attach_to_the_type_not_a_same_named_function() {
+    let symbols = extract_symbol_tree(IMPL_BEFORE_DECL_RUST).expect("impl_before_decl.rs should parse");
+
+    let baz_struct = symbols
+        .iter()
+        .find(|s| s.name == "Baz" && s.kind == SymbolKind::Struct)
+        .expect("Baz struct should be extracted");
+    assert_eq!(baz_struct.children.iter().map(|c| c.name.as_str()).collect::<Vec<_>>(), vec!["build"]);
+
+    let baz_fn = symbols
+        .iter()
+        .find(|s| s.name == "Baz" && s.kind == SymbolKind::Function)
+        .expect("Baz function should be extracted");
+    assert!(baz_fn.children.is_empty(), "the `build` method must not attach to the `Baz` function");
+}
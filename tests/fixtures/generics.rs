@@ -0,0 +1,33 @@
+// Fixture exercising generic parameters, inline bounds, and where-clauses.
+pub struct Pair<T, U> {
+    pub first: T,
+    pub second: U,
+}
+
+pub enum Either<L, R> {
+    Left(L),
+    Right(R),
+}
+
+impl<T, U> Pair<T, U> {
+    pub fn equals<A, B>(&self, a: &A, b: &B) -> bool
+    where
+        A: Eq,
+        B: Eq,
+    {
+        true
+    }
+}
+
+pub trait Equal {
+    fn is_equal(&self, other: &Self) -> bool;
+}
+
+impl<T> Equal for T
+where
+    T: Eq,
+{
+    fn is_equal(&self, other: &Self) -> bool {
+        self == other
+    }
+}
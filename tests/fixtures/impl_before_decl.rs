@@ -0,0 +1,21 @@
+// Fixture exercising two cases `extract_symbol_tree` must handle
+// regardless of textual order: an `impl` written before the struct it's
+// for, and a struct sharing a name with an unrelated function (legal,
+// since functions and types occupy different namespaces).
+impl Foo {
+    fn new() -> Self {
+        Foo {}
+    }
+}
+
+pub struct Foo {}
+
+fn Baz() {}
+
+pub struct Baz {}
+
+impl Baz {
+    fn build() -> Self {
+        Baz {}
+    }
+}
@@ -0,0 +1,31 @@
+// Fixture exercising inherent, trait, and blanket impls.
+pub struct Foo {}
+
+impl Foo {
+    pub fn new() -> Self {
+        Foo {}
+    }
+}
+
+pub trait Greet {
+    fn greet(&self) -> String;
+}
+
+impl Greet for Foo {
+    fn greet(&self) -> String {
+        "hi".to_string()
+    }
+}
+
+pub trait Equal {
+    fn is_equal(&self, other: &Self) -> bool;
+}
+
+impl<T> Equal for T
+where
+    T: Eq,
+{
+    fn is_equal(&self, other: &Self) -> bool {
+        self == other
+    }
+}
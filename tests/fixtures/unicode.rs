@@ -0,0 +1,12 @@
+// Fixture exercising identifiers outside ASCII, as permitted by Rust's
+// XID_Start/XID_Continue grammar (see request uicnz/codekite#chunk0-5).
+pub struct Δ {}
+
+pub enum Ωmega {
+    Α,
+    Β,
+}
+
+pub fn café() -> Δ {
+    Δ {}
+}
@@ -1,4 +1,11 @@
 // Golden Rust file for symbol extraction tests
+//
+// This file also compiles as its own (empty) integration test binary
+// since it lives directly under `tests/`, so it needs to satisfy clippy
+// on its own: the fixture intentionally has dead code and a constructor
+// without a `Default` impl, which isn't something we want flagged here.
+#![allow(dead_code, clippy::new_without_default)]
+
 pub struct Foo {}
 
 impl Foo {
@@ -0,0 +1,33 @@
+mod support;
+
+use std::io::Write;
+
+use codekite::TraitImplIndex;
+use support::mint;
+
+const IMPLS_RUST: &str = include_str!("fixtures/impls.rs");
+
+#[test]
+fn golden_impl_index() {
+    let index = TraitImplIndex::build(IMPLS_RUST).expect("impls.rs should parse");
+    let mut golden = mint().new_goldenfile("impls.json");
+    write!(golden, "{}", serde_json::to_string_pretty(&index).unwrap()).unwrap();
+}
+
+#[test]
+fn implementors_of_includes_blanket_impls() {
+    let index = TraitImplIndex::build(IMPLS_RUST).expect("impls.rs should parse");
+    let implementors = index.implementors_of("Equal");
+    assert_eq!(implementors.len(), 1);
+    assert!(implementors[0].is_blanket);
+    assert_eq!(implementors[0].where_predicates, vec![("T".to_string(), vec!["Eq".to_string()])]);
+}
+
+#[test]
+fn implementations_for_includes_inherent_and_trait_impls() {
+    let index = TraitImplIndex::build(IMPLS_RUST).expect("impls.rs should parse");
+    let mut traits: Vec<Option<&str>> =
+        index.implementations_for("Foo").into_iter().map(|imp| imp.trait_name.as_deref()).collect();
+    traits.sort();
+    assert_eq!(traits, vec![None, Some("Greet")]);
+}
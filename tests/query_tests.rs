@@ -0,0 +1,32 @@
+use codekite::query::WorkspaceSymbolIndex;
+use codekite::{extract_symbols_flat, SymbolKind};
+
+const GOLDEN_RUST: &str = include_str!("golden_rust.rs");
+
+fn index() -> WorkspaceSymbolIndex {
+    WorkspaceSymbolIndex::new(extract_symbols_flat(GOLDEN_RUST).expect("golden_rust.rs should parse"))
+}
+
+#[test]
+fn bare_term_matches_the_struct() {
+    let idx = index();
+    let names: Vec<&str> = idx.query("Foo").into_iter().map(|(s, _)| s.name.as_str()).collect();
+    assert_eq!(names, vec!["Foo"]);
+}
+
+#[test]
+fn hash_suffix_matches_the_method() {
+    let idx = index();
+    let results = idx.query("bar#");
+    assert_eq!(results.len(), 1);
+    assert_eq!(results[0].0.name, "bar");
+    assert_eq!(results[0].0.kind, SymbolKind::Method);
+}
+
+#[test]
+fn prefix_matches_enum_and_trait() {
+    let idx = index();
+    let mut names: Vec<&str> = idx.query("My").into_iter().map(|(s, _)| s.name.as_str()).collect();
+    names.sort();
+    assert_eq!(names, vec!["MyEnum", "MyTrait"]);
+}
@@ -0,0 +1,88 @@
+//! Self-updating golden-file test harness.
+//!
+//! Tests write their actual output through a [`GoldenFile`] handle
+//! obtained from [`Mint::new_goldenfile`]. On `Drop`, the handle compares
+//! the written bytes against the committed golden file and fails the test
+//! (via `assert_eq!`, so the standard expected/actual output is shown) on
+//! mismatch. Set `CODEKITE_UPDATE_GOLDEN=1` to rewrite the golden files in
+//! place instead of asserting against them, e.g.:
+//!
+//! ```text
+//! CODEKITE_UPDATE_GOLDEN=1 cargo test --test extractor_tests
+//! ```
+
+use std::env;
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::thread;
+
+/// Golden-file harness rooted at this crate's `tests/golden` directory.
+pub fn mint() -> Mint {
+    Mint::new(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/golden"))
+}
+
+/// Hands out [`GoldenFile`]s rooted at a single golden-file directory.
+pub struct Mint {
+    golden_dir: PathBuf,
+}
+
+impl Mint {
+    pub fn new(golden_dir: impl Into<PathBuf>) -> Self {
+        Self { golden_dir: golden_dir.into() }
+    }
+
+    /// Opens a golden file named `name` (relative to this mint's
+    /// directory) for writing.
+    pub fn new_goldenfile(&self, name: impl AsRef<Path>) -> GoldenFile {
+        GoldenFile { path: self.golden_dir.join(name.as_ref()), buf: Vec::new() }
+    }
+}
+
+/// A write handle for a single golden file, diffed (or updated) on drop.
+pub struct GoldenFile {
+    path: PathBuf,
+    buf: Vec<u8>,
+}
+
+impl Write for GoldenFile {
+    fn write(&mut self, data: &[u8]) -> std::io::Result<usize> {
+        self.buf.write(data)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+impl Drop for GoldenFile {
+    fn drop(&mut self) {
+        // Don't pile a second panic on top of one already unwinding the
+        // test, and don't rewrite the golden on a run that's already failing.
+        if thread::panicking() {
+            return;
+        }
+
+        let actual = String::from_utf8_lossy(&self.buf).into_owned();
+
+        if env::var_os("CODEKITE_UPDATE_GOLDEN").is_some() {
+            fs::write(&self.path, &actual)
+                .unwrap_or_else(|err| panic!("failed to write golden file {}: {err}", self.path.display()));
+            return;
+        }
+
+        let expected = fs::read_to_string(&self.path).unwrap_or_else(|err| {
+            panic!(
+                "failed to read golden file {} ({err}); run with CODEKITE_UPDATE_GOLDEN=1 to create it",
+                self.path.display(),
+            )
+        });
+
+        assert_eq!(
+            actual.trim_end(),
+            expected.trim_end(),
+            "{} is out of date; re-run with CODEKITE_UPDATE_GOLDEN=1 to update it",
+            self.path.display(),
+        );
+    }
+}